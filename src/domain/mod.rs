@@ -0,0 +1,3 @@
+//! Domain types shared across handlers. Reserved for the entities the API
+//! will grow to expose; empty for now since only the health/readiness
+//! surface exists.
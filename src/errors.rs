@@ -0,0 +1,10 @@
+use axum::http::StatusCode;
+
+// Maps any error implementing `std::error::Error` into a `500 Internal
+// Server Error` response, carrying the error's `Display` output as the body.
+pub fn internal_error<E>(err: E) -> (StatusCode, String)
+where
+    E: std::error::Error,
+{
+    (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+}
@@ -0,0 +1,63 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use diesel_async::RunQueryDsl;
+
+use crate::errors::internal_error;
+use crate::AppState;
+
+// Liveness probe: if the process can answer HTTP requests at all, it's alive.
+pub async fn health() -> StatusCode {
+    StatusCode::OK
+}
+
+// Readiness probe: pulls a connection from the pool and round-trips a
+// trivial query, so orchestrators only route traffic here once the
+// database is actually reachable.
+pub async fn ready(State(state): State<AppState>) -> Result<StatusCode, (StatusCode, String)> {
+    let result = async {
+        let mut conn = state.pool.get().await.map_err(internal_error)?;
+        diesel::sql_query("SELECT 1")
+            .execute(&mut conn)
+            .await
+            .map_err(internal_error)?;
+
+        Ok::<(), (StatusCode, String)>(())
+    }
+    .await;
+
+    ready_response(result)
+}
+
+// Maps the DB round-trip result to the readiness contract: 200 on success,
+// 503 carrying the failure reason otherwise. Split out from `ready` so the
+// mapping is testable without a live pool.
+fn ready_response(
+    result: Result<(), (StatusCode, String)>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    match result {
+        Ok(()) => Ok(StatusCode::OK),
+        Err((_, reason)) => Err((StatusCode::SERVICE_UNAVAILABLE, reason)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_success_to_200() {
+        assert_eq!(ready_response(Ok(())), Ok(StatusCode::OK));
+    }
+
+    #[test]
+    fn maps_failure_to_503_with_reason() {
+        let err = (StatusCode::INTERNAL_SERVER_ERROR, "connection refused".to_string());
+        assert_eq!(
+            ready_response(Err(err)),
+            Err((
+                StatusCode::SERVICE_UNAVAILABLE,
+                "connection refused".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,5 @@
+//! Infrastructure concerns (database access, external clients). Reserved
+//! for the persistence layer the API will grow to use beyond the
+//! connection pool it already centralizes here.
+
+pub mod pool;
@@ -0,0 +1,14 @@
+use deadpool::managed::BuildError;
+use diesel_async::pooled_connection::deadpool::Pool;
+use diesel_async::pooled_connection::AsyncDieselConnectionManager;
+use diesel_async::AsyncPgConnection;
+
+pub type DbPool = Pool<AsyncPgConnection>;
+
+// Builds the async connection pool backing `AppState`. Centralized here so
+// the manager/runtime wiring isn't duplicated if another entry point
+// (tests, a worker binary) ever needs its own pool.
+pub fn build_pool(db_url: &str) -> Result<DbPool, BuildError> {
+    let manager = AsyncDieselConnectionManager::<AsyncPgConnection>::new(db_url);
+    Pool::builder(manager).build()
+}
@@ -1,11 +1,16 @@
 use std::net::SocketAddr;
+use std::process::ExitCode;
 
-use deadpool_diesel::postgres::{Manager, Pool};
+use clap::{Parser, Subcommand};
+use diesel::Connection;
+use diesel_async::async_connection_wrapper::AsyncConnectionWrapper;
+use diesel_async::AsyncPgConnection;
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
-use crate::config::config;
+use crate::config::{config, Config};
 use crate::errors::internal_error;
+use crate::infra::pool::{build_pool, DbPool};
 use crate::routes::app_router;
 
 // Import modules
@@ -22,52 +27,168 @@ pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations/");
 // Struct to hold the application state
 #[derive(Clone)]
 pub struct AppState {
-    pool: Pool,
+    pool: DbPool,
+}
+
+#[derive(Parser)]
+#[command(name = "axum-dieasel-api", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the HTTP server.
+    Serve {
+        /// Host to bind to. Overrides the configured `server_host`.
+        #[arg(long, env = "SERVER_HOST")]
+        host: Option<String>,
+        /// Port to bind to. Overrides the configured `server_port`.
+        #[arg(long, env = "SERVER_PORT")]
+        port: Option<u16>,
+    },
+    /// Apply or inspect database migrations.
+    Migrate {
+        #[command(subcommand)]
+        command: MigrateCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum MigrateCommand {
+    /// Apply all pending migrations.
+    Run,
+    /// List applied and pending migrations without applying anything.
+    Status,
 }
 
 // Main function, the entry point of the application
 #[tokio::main]
-async fn main() {
+async fn main() -> ExitCode {
     // Initialize tracing for logging
     init_tracing();
 
+    let cli = Cli::parse();
+
     // Load configuration settings
-    let config = config().await;
+    let config = match config() {
+        Ok(config) => config,
+        Err(err) => {
+            tracing::error!("failed to load configuration: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match cli.command {
+        Command::Serve { host, port } => serve(&config, host, port).await,
+        Command::Migrate { command } => migrate(&config, command).await,
+    }
+}
 
-    // Create a connection pool to the PostgresSQL database
-    let manager = Manager::new(
-        config.db_url().to_string(),
-        deadpool_diesel::Runtime::Tokio1,
-    );
-    let pool = Pool::builder(manager).build().unwrap();
+// Apply pending migrations, bring up the application state, and serve HTTP
+// traffic until the process is asked to stop.
+async fn serve(config: &Config, host: Option<String>, port: Option<u16>) -> ExitCode {
+    if let Err(err) = run_migrations(config.db_url()).await {
+        tracing::error!("failed to apply migrations: {err}");
+        return ExitCode::FAILURE;
+    }
 
-    // Apply pending database migrations
-    run_migrations(&pool).await;
+    // Build the async connection pool the rest of the app queries through.
+    let pool = match build_pool(config.db_url()) {
+        Ok(pool) => pool,
+        Err(err) => {
+            tracing::error!("failed to build database pool: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
 
     // Create an instance of the application state
     let state = AppState { pool };
 
     // Configure the application router
-    let app = app_router(state.clone()).with_state(state);
+    let app = app_router().with_state(state);
 
-    // Define the host and port for the server
-    let host = config.server_host();
-    let port = config.server_port();
+    // Define the host and port for the server, letting CLI flags win over config
+    let host = host.unwrap_or_else(|| config.server_host().to_string());
+    let port = port.unwrap_or_else(|| config.server_port());
 
     let address = format!("{}:{}", host, port);
 
     // Parse the socket address
-    let socket_addr: SocketAddr = address.parse().unwrap();
+    let socket_addr: SocketAddr = match address.parse() {
+        Ok(addr) => addr,
+        Err(err) => {
+            tracing::error!("invalid server address `{address}`: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
 
     // Log the server's listening address
     tracing::info!("listening on http://{}", socket_addr);
 
-    // Start the Axum server
-    axum::Server::bind(&socket_addr)
+    // Start the Axum server, draining in-flight requests on shutdown signal
+    // instead of dropping them mid-response on redeploy.
+    if let Err((_, message)) = axum::Server::bind(&socket_addr)
         .serve(app.into_make_service())
+        .with_graceful_shutdown(shutdown_signal())
         .await
         .map_err(internal_error)
-        .unwrap()
+    {
+        tracing::error!("server error: {message}");
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
+
+// Resolves once Ctrl-C or, on Unix, SIGTERM is received, logging which one
+// triggered the shutdown so graceful-shutdown logs are unambiguous.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to install Ctrl-C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => tracing::info!("received Ctrl-C, shutting down"),
+        _ = terminate => tracing::info!("received SIGTERM, shutting down"),
+    }
+}
+
+// Apply or report on database migrations, then exit without serving.
+async fn migrate(config: &Config, command: MigrateCommand) -> ExitCode {
+    match command {
+        MigrateCommand::Run => match run_migrations(config.db_url()).await {
+            Ok(()) => {
+                tracing::info!("migrations applied");
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                tracing::error!("failed to apply migrations: {err}");
+                ExitCode::FAILURE
+            }
+        },
+        MigrateCommand::Status => match migration_status(config.db_url()).await {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                tracing::error!("failed to read migration status: {err}");
+                ExitCode::FAILURE
+            }
+        },
+    }
 }
 
 // Function to initialize tracing for logging
@@ -81,11 +202,55 @@ fn init_tracing() {
         .init();
 }
 
-// Function to run database migrations
-async fn run_migrations(pool: &Pool) {
-    let conn = pool.get().await.unwrap();
-    conn.interact(|conn| conn.run_pending_migrations(MIGRATIONS).map(|_| ()))
-        .await
-        .unwrap()
-        .unwrap();
-}
\ No newline at end of file
+// `diesel_async` has no embedded-migration runner of its own, so migrations
+// still need a sync `diesel::Connection`. We open one throwaway connection
+// (`AsyncConnectionWrapper` drives an `AsyncPgConnection` under a blocking
+// handle), run pending migrations on it, and let it close before the app's
+// long-lived async pool is built — connect, migrate, then pool.
+async fn run_migrations(db_url: &str) -> Result<(), String> {
+    let db_url = db_url.to_string();
+    tokio::task::spawn_blocking(move || {
+        let mut conn = AsyncConnectionWrapper::<AsyncPgConnection>::establish(&db_url)
+            .map_err(|err| err.to_string())?;
+        conn.run_pending_migrations(MIGRATIONS)
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    })
+    .await
+    .map_err(|err| err.to_string())?
+}
+
+// Print applied and pending migration names without running anything, using
+// the same throwaway-connection approach as `run_migrations`.
+async fn migration_status(db_url: &str) -> Result<(), String> {
+    let db_url = db_url.to_string();
+    tokio::task::spawn_blocking(move || {
+        let mut conn = AsyncConnectionWrapper::<AsyncPgConnection>::establish(&db_url)
+            .map_err(|err| err.to_string())?;
+
+        let known = diesel::migration::MigrationSource::<diesel::pg::Pg>::migrations(&MIGRATIONS)
+            .map_err(|err| err.to_string())?;
+        let applied = conn.applied_migrations().map_err(|err| err.to_string())?;
+        println!("applied:");
+        for version in &applied {
+            // The database records only versions; take the name from the
+            // embedded set, falling back to the bare version if it is unknown.
+            match known.iter().find(|m| m.name().version() == *version) {
+                Some(migration) => println!("  {}", migration.name()),
+                None => println!("  {version}"),
+            }
+        }
+
+        let pending = conn
+            .pending_migrations(MIGRATIONS)
+            .map_err(|err| err.to_string())?;
+        println!("pending:");
+        for migration in &pending {
+            println!("  {}", migration.name());
+        }
+
+        Ok(())
+    })
+    .await
+    .map_err(|err| err.to_string())?
+}
@@ -0,0 +1,46 @@
+use axum::extract::MatchedPath;
+use axum::http::Request;
+use axum::{routing::get, Router};
+use tower::ServiceBuilder;
+use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
+use tower_http::trace::TraceLayer;
+use tracing::info_span;
+
+use crate::handlers::health::{health, ready};
+use crate::AppState;
+
+// Wires up all HTTP routes.
+pub fn app_router() -> Router<AppState> {
+    Router::new()
+        .route("/health", get(health))
+        .route("/ready", get(ready))
+        .layer(
+            ServiceBuilder::new()
+                // Stamp a request id before anything else sees the request.
+                .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
+                // Build a span per request, keyed on the matched route
+                // template (not the raw URI) to keep span cardinality low.
+                .layer(TraceLayer::new_for_http().make_span_with(|request: &Request<_>| {
+                    let route = request
+                        .extensions()
+                        .get::<MatchedPath>()
+                        .map(MatchedPath::as_str)
+                        .unwrap_or("-");
+
+                    let request_id = request
+                        .headers()
+                        .get("x-request-id")
+                        .and_then(|value| value.to_str().ok())
+                        .unwrap_or("-");
+
+                    info_span!(
+                        "request",
+                        method = %request.method(),
+                        route,
+                        request_id,
+                    )
+                }))
+                // Echo the request id back so clients can correlate.
+                .layer(PropagateRequestIdLayer::x_request_id()),
+        )
+}